@@ -1,41 +1,369 @@
 use crate::{ElementState, Input};
 use bevy_app::prelude::*;
-use bevy_ecs::ResMut;
+use bevy_ecs::{Res, ResMut};
+use std::collections::{HashMap, HashSet};
 use std::convert::TryInto;
+use std::hash::Hash;
 
-/// A key input event from a keyboard device
+/// A key input event from a keyboard device.
+///
+/// Carries both the layout-independent `physical_key` (suitable for things like WASD movement
+/// bindings, which should stay put regardless of the user's keyboard layout) and the
+/// layout-interpreted `logical_key` plus committed `text` (suitable for displaying what the user
+/// actually typed).
 #[derive(Debug, Clone)]
 pub struct KeyboardInput {
     pub scan_code: u32,
-    pub key_code: Option<KeyCode>,
+    /// The layout-independent identifier of the key that was pressed or released, derived from
+    /// `scan_code`. Stable across QWERTY, AZERTY, Dvorak, etc.
+    ///
+    /// Always present: a scan code with no matching named variant becomes
+    /// `KeyCode::Unidentified`, so unusual or region-specific keys still register instead of
+    /// being silently dropped.
+    pub physical_key: KeyCode,
+    /// The layout-interpreted key, e.g. the character the user actually typed.
+    pub logical_key: Key,
+    /// The text committed by this keypress, if any. May contain more than one character for
+    /// IME composition.
+    pub text: Option<String>,
+    /// Which physical location the key was pressed at, e.g. left vs right Shift.
+    pub location: KeyLocation,
+    /// `state` is `ElementState::Pressed` for both the initial press and any OS auto-repeat
+    /// presses that follow while the key is held; `repeat` distinguishes the two.
     pub state: ElementState,
+    /// Whether this event was generated by the OS's key auto-repeat rather than a fresh press.
+    pub repeat: bool,
 }
 
-/// Updates the Input<KeyCode> resource with the latest KeyboardInput events
+/// The logical key produced by a [`KeyboardInput`] event, after OS/layout interpretation.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum Key {
+    /// The key produced this character, e.g. `'A'` for Shift+A or `'@'` for Shift+2 on some
+    /// layouts.
+    Character(char),
+    /// A key with no direct textual representation, identified by its physical-independent name.
+    Named(KeyCode),
+    /// The platform could not determine a logical key for this event.
+    Unidentified,
+}
+
+/// Which physical location a key was pressed at, distinguishing e.g. left and right Shift or a
+/// Numpad digit from its non-numpad counterpart.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum KeyLocation {
+    /// A key with only one location, or whose location is not significant.
+    Standard,
+    /// The left side variant of a key that comes in pairs, e.g. the left Shift.
+    Left,
+    /// The right side variant of a key that comes in pairs, e.g. the right Shift.
+    Right,
+    /// A key on the numpad.
+    Numpad,
+}
+
+/// Updates the `Input<KeyCode>`, `Input<Key>`, and [`Modifiers`] resources with the latest
+/// `KeyboardInput` events.
 pub fn keyboard_input_system(
     mut keyboard_input: ResMut<Input<KeyCode>>,
+    mut key_input: ResMut<Input<Key>>,
+    mut modifiers: ResMut<Modifiers>,
     mut keyboard_input_events: EventReader<KeyboardInput>,
 ) {
     keyboard_input.update();
+    key_input.update();
     for event in keyboard_input_events.iter() {
-        if let KeyboardInput {
-            key_code: Some(key_code),
+        let KeyboardInput {
+            physical_key,
+            logical_key,
             state,
+            repeat,
             ..
-        } = event
-        {
-            match state {
-                ElementState::Pressed => keyboard_input.press(*key_code),
-                ElementState::Released => keyboard_input.release(*key_code),
+        } = event;
+
+        match state {
+            ElementState::Pressed => {
+                // Auto-repeat presses shouldn't re-trigger `just_pressed`; the key is already
+                // held down.
+                if !repeat {
+                    keyboard_input.press(*physical_key);
+                }
+            }
+            ElementState::Released => keyboard_input.release(*physical_key),
+        }
+
+        match state {
+            ElementState::Pressed => {
+                if !repeat {
+                    key_input.press(*logical_key);
+                }
+            }
+            ElementState::Released => key_input.release(*logical_key),
+        }
+
+        modifiers.update(*physical_key, *state == ElementState::Pressed);
+    }
+}
+
+/// Tracks which modifier keys are currently held, distinguishing left and right where the
+/// hardware does (e.g. `LShift` vs `RShift`), updated by [`keyboard_input_system`].
+///
+/// Games and editor tooling should read this instead of manually checking four pairs of
+/// `KeyCode`s every frame.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Modifiers {
+    bits: u8,
+}
+
+impl Modifiers {
+    const SHIFT_LEFT: u8 = 1 << 0;
+    const SHIFT_RIGHT: u8 = 1 << 1;
+    const CONTROL_LEFT: u8 = 1 << 2;
+    const CONTROL_RIGHT: u8 = 1 << 3;
+    const ALT_LEFT: u8 = 1 << 4;
+    const ALT_RIGHT: u8 = 1 << 5;
+    // Bevy calls this key `LWin`/`RWin` on `KeyCode`; it's the same key as "Super" or "Meta".
+    const WIN_LEFT: u8 = 1 << 6;
+    const WIN_RIGHT: u8 = 1 << 7;
+
+    /// Updates the tracked state for `key`, if it's one of the modifier keys. No-op otherwise.
+    pub fn update(&mut self, key: KeyCode, pressed: bool) {
+        let bit = match key {
+            KeyCode::LShift => Self::SHIFT_LEFT,
+            KeyCode::RShift => Self::SHIFT_RIGHT,
+            KeyCode::LControl => Self::CONTROL_LEFT,
+            KeyCode::RControl => Self::CONTROL_RIGHT,
+            KeyCode::LAlt => Self::ALT_LEFT,
+            KeyCode::RAlt => Self::ALT_RIGHT,
+            KeyCode::LWin => Self::WIN_LEFT,
+            KeyCode::RWin => Self::WIN_RIGHT,
+            _ => return,
+        };
+        if pressed {
+            self.bits |= bit;
+        } else {
+            self.bits &= !bit;
+        }
+    }
+
+    /// Whether either Shift key is held.
+    pub fn shift(&self) -> bool {
+        self.bits & (Self::SHIFT_LEFT | Self::SHIFT_RIGHT) != 0
+    }
+    /// Whether the left Shift key is held.
+    pub fn left_shift(&self) -> bool {
+        self.bits & Self::SHIFT_LEFT != 0
+    }
+    /// Whether the right Shift key is held.
+    pub fn right_shift(&self) -> bool {
+        self.bits & Self::SHIFT_RIGHT != 0
+    }
+
+    /// Whether either Control key is held.
+    pub fn control(&self) -> bool {
+        self.bits & (Self::CONTROL_LEFT | Self::CONTROL_RIGHT) != 0
+    }
+    /// Whether the left Control key is held.
+    pub fn left_control(&self) -> bool {
+        self.bits & Self::CONTROL_LEFT != 0
+    }
+    /// Whether the right Control key is held.
+    pub fn right_control(&self) -> bool {
+        self.bits & Self::CONTROL_RIGHT != 0
+    }
+
+    /// Whether either Alt key is held.
+    pub fn alt(&self) -> bool {
+        self.bits & (Self::ALT_LEFT | Self::ALT_RIGHT) != 0
+    }
+    /// Whether the left Alt key is held.
+    pub fn left_alt(&self) -> bool {
+        self.bits & Self::ALT_LEFT != 0
+    }
+    /// Whether the right Alt key is held.
+    pub fn right_alt(&self) -> bool {
+        self.bits & Self::ALT_RIGHT != 0
+    }
+
+    /// Whether either Super/Meta/Windows key (`LWin`/`RWin`) is held.
+    pub fn win(&self) -> bool {
+        self.bits & (Self::WIN_LEFT | Self::WIN_RIGHT) != 0
+    }
+    /// Whether the left Super/Meta/Windows key is held.
+    pub fn left_win(&self) -> bool {
+        self.bits & Self::WIN_LEFT != 0
+    }
+    /// Whether the right Super/Meta/Windows key is held.
+    pub fn right_win(&self) -> bool {
+        self.bits & Self::WIN_RIGHT != 0
+    }
+}
+
+/// Formats `key` plus `modifiers` as a chord string suitable for displaying or saving a hotkey,
+/// e.g. `"Ctrl+Shift+A"`.
+pub fn format_chord(key: KeyCode, modifiers: Modifiers) -> String {
+    let mut parts = Vec::new();
+    if modifiers.control() {
+        parts.push("Ctrl".to_string());
+    }
+    if modifiers.alt() {
+        parts.push("Alt".to_string());
+    }
+    if modifiers.shift() {
+        parts.push("Shift".to_string());
+    }
+    if modifiers.win() {
+        parts.push("Super".to_string());
+    }
+    parts.push(key.chord_label());
+    parts.join("+")
+}
+
+/// Formats `key` plus `modifiers` as a compact, Emacs-style chord string, e.g. `"C-S-a"`.
+pub fn format_chord_compact(key: KeyCode, modifiers: Modifiers) -> String {
+    let mut chord = String::new();
+    if modifiers.control() {
+        chord.push_str("C-");
+    }
+    if modifiers.alt() {
+        chord.push_str("M-");
+    }
+    if modifiers.shift() {
+        chord.push_str("S-");
+    }
+    if modifiers.win() {
+        chord.push_str("s-");
+    }
+    chord.push_str(&key.chord_label().to_lowercase());
+    chord
+}
+
+#[cfg(test)]
+mod chord_format_tests {
+    use super::*;
+
+    fn modifiers(keys: &[KeyCode]) -> Modifiers {
+        let mut modifiers = Modifiers::default();
+        for key in keys {
+            modifiers.update(*key, true);
+        }
+        modifiers
+    }
+
+    #[test]
+    fn formats_ctrl_shift_a() {
+        let modifiers = modifiers(&[KeyCode::LControl, KeyCode::LShift]);
+
+        assert_eq!(format_chord(KeyCode::A, modifiers), "Ctrl+Shift+A");
+        assert_eq!(format_chord_compact(KeyCode::A, modifiers), "C-S-a");
+    }
+
+    #[test]
+    fn formats_plain_key_with_no_modifiers() {
+        let modifiers = Modifiers::default();
+
+        assert_eq!(format_chord(KeyCode::A, modifiers), "A");
+        assert_eq!(format_chord_compact(KeyCode::A, modifiers), "a");
+    }
+
+    #[test]
+    fn formats_super_s() {
+        let modifiers = modifiers(&[KeyCode::LWin]);
+
+        assert_eq!(format_chord(KeyCode::S, modifiers), "Super+S");
+        assert_eq!(format_chord_compact(KeyCode::S, modifiers), "s-s");
+    }
+}
+
+/// A single Unicode character produced by a keypress, already resolved by the OS for the
+/// current layout and modifiers (e.g. `'A'` for Shift+A, `'é'` for a French layout's `é` key).
+///
+/// Sourced independently of `KeyCode`, so it's correct under any layout or modifier combination,
+/// unlike `TryInto<char> for KeyCode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReceivedCharacter {
+    pub char: char,
+}
+
+/// A string committed by the platform's input method, used for multi-codepoint input such as
+/// CJK IME composition where no single `ReceivedCharacter` can represent the result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceivedString {
+    pub value: String,
+}
+
+/// Accumulates text from `ReceivedCharacter` and `ReceivedString` events so UI widgets can read a
+/// finished string, handling Backspace/Delete.
+///
+/// This resource has no notion of a cursor: Backspace and Delete both remove the last character
+/// of the buffer. Widgets that need an editable cursor position should track one themselves and
+/// consume `ReceivedCharacter`/`ReceivedString` directly instead of using this resource.
+#[derive(Debug, Clone, Default)]
+pub struct TextInput {
+    buffer: String,
+}
+
+impl TextInput {
+    /// The text accumulated so far.
+    pub fn buffer(&self) -> &str {
+        &self.buffer
+    }
+
+    /// Removes and returns the accumulated text, resetting the buffer to empty.
+    pub fn take(&mut self) -> String {
+        std::mem::take(&mut self.buffer)
+    }
+
+    /// Clears the accumulated text without returning it.
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+    }
+}
+
+/// Feeds `ReceivedCharacter` and `ReceivedString` events into the `TextInput` resource, and
+/// removes the last character on Backspace/Delete.
+pub fn text_input_system(
+    mut text_input: ResMut<TextInput>,
+    mut char_events: EventReader<ReceivedCharacter>,
+    mut string_events: EventReader<ReceivedString>,
+    mut keyboard_input_events: EventReader<KeyboardInput>,
+) {
+    for event in char_events.iter() {
+        text_input.buffer.push(event.char);
+    }
+    for event in string_events.iter() {
+        text_input.buffer.push_str(&event.value);
+    }
+    for event in keyboard_input_events.iter() {
+        if event.state != ElementState::Pressed {
+            continue;
+        }
+        match event.physical_key {
+            KeyCode::Back | KeyCode::Delete => {
+                text_input.buffer.pop();
             }
+            _ => {}
         }
     }
 }
 
+/// A raw, platform-specific keycode that didn't correspond to any named [`KeyCode`] variant.
+///
+/// Keeping the native code (instead of discarding the press, as used to happen) lets media keys,
+/// extra mouse-side buttons, and other region- or vendor-specific keys still flow through
+/// `Input<KeyCode>` and be bound by users, even though there's no portable name for them.
+#[derive(Debug, Hash, Ord, PartialOrd, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct NativeKeyCode(pub u32);
+
 /// The key code of a keyboard input.
+///
+/// This enum is open-ended: a scan code that doesn't correspond to any of the named variants
+/// below becomes [`KeyCode::Unidentified`] rather than being dropped, so unusual keys still round
+/// trip through the rest of the input pipeline.
 #[derive(Debug, Hash, Ord, PartialOrd, PartialEq, Eq, Clone, Copy)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
-#[repr(u32)]
 pub enum KeyCode {
     /// The '1' key over the letters.
     Key1,
@@ -230,65 +558,874 @@ pub enum KeyCode {
     Copy,
     Paste,
     Cut,
+
+    /// A key whose scan code doesn't correspond to any of the named variants above, e.g. a media
+    /// key or a region-specific key on an unusual keyboard. The native code is preserved so the
+    /// key still registers and can be bound.
+    Unidentified(NativeKeyCode),
+}
+
+/// Every fieldless [`KeyCode`] variant, in declaration order. Used to drive the string
+/// conversions below.
+///
+/// This is itself a hand-maintained table alongside [`KeyCode::code_name`]'s match, so it can
+/// silently fall out of sync when a variant is added: `code_name` is exhaustive and the compiler
+/// forces an arm for the new variant, but nothing forces it to be added here too, and a missing
+/// entry means `FromStr` can no longer parse that variant's own `Display` output. The
+/// `all_key_codes_covers_every_variant` test below guards against that gap.
+const ALL_KEY_CODES: &[KeyCode] = &[
+    KeyCode::Key1,
+    KeyCode::Key2,
+    KeyCode::Key3,
+    KeyCode::Key4,
+    KeyCode::Key5,
+    KeyCode::Key6,
+    KeyCode::Key7,
+    KeyCode::Key8,
+    KeyCode::Key9,
+    KeyCode::Key0,
+    KeyCode::A,
+    KeyCode::B,
+    KeyCode::C,
+    KeyCode::D,
+    KeyCode::E,
+    KeyCode::F,
+    KeyCode::G,
+    KeyCode::H,
+    KeyCode::I,
+    KeyCode::J,
+    KeyCode::K,
+    KeyCode::L,
+    KeyCode::M,
+    KeyCode::N,
+    KeyCode::O,
+    KeyCode::P,
+    KeyCode::Q,
+    KeyCode::R,
+    KeyCode::S,
+    KeyCode::T,
+    KeyCode::U,
+    KeyCode::V,
+    KeyCode::W,
+    KeyCode::X,
+    KeyCode::Y,
+    KeyCode::Z,
+    KeyCode::Escape,
+    KeyCode::F1,
+    KeyCode::F2,
+    KeyCode::F3,
+    KeyCode::F4,
+    KeyCode::F5,
+    KeyCode::F6,
+    KeyCode::F7,
+    KeyCode::F8,
+    KeyCode::F9,
+    KeyCode::F10,
+    KeyCode::F11,
+    KeyCode::F12,
+    KeyCode::F13,
+    KeyCode::F14,
+    KeyCode::F15,
+    KeyCode::F16,
+    KeyCode::F17,
+    KeyCode::F18,
+    KeyCode::F19,
+    KeyCode::F20,
+    KeyCode::F21,
+    KeyCode::F22,
+    KeyCode::F23,
+    KeyCode::F24,
+    KeyCode::Snapshot,
+    KeyCode::Scroll,
+    KeyCode::Pause,
+    KeyCode::Insert,
+    KeyCode::Home,
+    KeyCode::Delete,
+    KeyCode::End,
+    KeyCode::PageDown,
+    KeyCode::PageUp,
+    KeyCode::Left,
+    KeyCode::Up,
+    KeyCode::Right,
+    KeyCode::Down,
+    KeyCode::Back,
+    KeyCode::Return,
+    KeyCode::Space,
+    KeyCode::Compose,
+    KeyCode::Caret,
+    KeyCode::Numlock,
+    KeyCode::Numpad0,
+    KeyCode::Numpad1,
+    KeyCode::Numpad2,
+    KeyCode::Numpad3,
+    KeyCode::Numpad4,
+    KeyCode::Numpad5,
+    KeyCode::Numpad6,
+    KeyCode::Numpad7,
+    KeyCode::Numpad8,
+    KeyCode::Numpad9,
+    KeyCode::AbntC1,
+    KeyCode::AbntC2,
+    KeyCode::NumpadAdd,
+    KeyCode::Apostrophe,
+    KeyCode::Apps,
+    KeyCode::Asterisk,
+    KeyCode::Plus,
+    KeyCode::At,
+    KeyCode::Ax,
+    KeyCode::Backslash,
+    KeyCode::Calculator,
+    KeyCode::Capital,
+    KeyCode::Colon,
+    KeyCode::Comma,
+    KeyCode::Convert,
+    KeyCode::NumpadDecimal,
+    KeyCode::NumpadDivide,
+    KeyCode::Equals,
+    KeyCode::Grave,
+    KeyCode::Kana,
+    KeyCode::Kanji,
+    KeyCode::LAlt,
+    KeyCode::LBracket,
+    KeyCode::LControl,
+    KeyCode::LShift,
+    KeyCode::LWin,
+    KeyCode::Mail,
+    KeyCode::MediaSelect,
+    KeyCode::MediaStop,
+    KeyCode::Minus,
+    KeyCode::NumpadMultiply,
+    KeyCode::Mute,
+    KeyCode::MyComputer,
+    KeyCode::NavigateForward,
+    KeyCode::NavigateBackward,
+    KeyCode::NextTrack,
+    KeyCode::NoConvert,
+    KeyCode::NumpadComma,
+    KeyCode::NumpadEnter,
+    KeyCode::NumpadEquals,
+    KeyCode::OEM102,
+    KeyCode::Period,
+    KeyCode::PlayPause,
+    KeyCode::Power,
+    KeyCode::PrevTrack,
+    KeyCode::RAlt,
+    KeyCode::RBracket,
+    KeyCode::RControl,
+    KeyCode::RShift,
+    KeyCode::RWin,
+    KeyCode::Semicolon,
+    KeyCode::Slash,
+    KeyCode::Sleep,
+    KeyCode::Stop,
+    KeyCode::NumpadSubtract,
+    KeyCode::Sysrq,
+    KeyCode::Tab,
+    KeyCode::Underline,
+    KeyCode::Unlabeled,
+    KeyCode::VolumeDown,
+    KeyCode::VolumeUp,
+    KeyCode::Wake,
+    KeyCode::WebBack,
+    KeyCode::WebFavorites,
+    KeyCode::WebForward,
+    KeyCode::WebHome,
+    KeyCode::WebRefresh,
+    KeyCode::WebSearch,
+    KeyCode::WebStop,
+    KeyCode::Yen,
+    KeyCode::Copy,
+    KeyCode::Paste,
+    KeyCode::Cut,
+];
+
+impl KeyCode {
+    /// All fieldless [`KeyCode`] variants, in declaration order. `KeyCode::Unidentified` is not
+    /// included.
+    pub fn all() -> &'static [KeyCode] {
+        ALL_KEY_CODES
+    }
+
+    /// Returns the canonical W3C UI Events `code` string for this key, e.g. `"KeyA"` or
+    /// `"ArrowLeft"`. Keys with no W3C `code` (e.g. `Caret`, `Ax`, `Apps`) get a name of their
+    /// own instead, such as `"Caret"`, `"Ax"` or `"ContextMenu2"`.
+    ///
+    /// This is the identifier web browsers (and other engines that adopted the same scheme)
+    /// report for `KeyboardEvent.code`, and is stable across keyboard layouts. It's suitable for
+    /// storing rebindable controls in a human-editable config file.
+    pub fn code_name(&self) -> &'static str {
+        match self {
+            KeyCode::Key1 => "Digit1",
+            KeyCode::Key2 => "Digit2",
+            KeyCode::Key3 => "Digit3",
+            KeyCode::Key4 => "Digit4",
+            KeyCode::Key5 => "Digit5",
+            KeyCode::Key6 => "Digit6",
+            KeyCode::Key7 => "Digit7",
+            KeyCode::Key8 => "Digit8",
+            KeyCode::Key9 => "Digit9",
+            KeyCode::Key0 => "Digit0",
+            KeyCode::A => "KeyA",
+            KeyCode::B => "KeyB",
+            KeyCode::C => "KeyC",
+            KeyCode::D => "KeyD",
+            KeyCode::E => "KeyE",
+            KeyCode::F => "KeyF",
+            KeyCode::G => "KeyG",
+            KeyCode::H => "KeyH",
+            KeyCode::I => "KeyI",
+            KeyCode::J => "KeyJ",
+            KeyCode::K => "KeyK",
+            KeyCode::L => "KeyL",
+            KeyCode::M => "KeyM",
+            KeyCode::N => "KeyN",
+            KeyCode::O => "KeyO",
+            KeyCode::P => "KeyP",
+            KeyCode::Q => "KeyQ",
+            KeyCode::R => "KeyR",
+            KeyCode::S => "KeyS",
+            KeyCode::T => "KeyT",
+            KeyCode::U => "KeyU",
+            KeyCode::V => "KeyV",
+            KeyCode::W => "KeyW",
+            KeyCode::X => "KeyX",
+            KeyCode::Y => "KeyY",
+            KeyCode::Z => "KeyZ",
+            KeyCode::Escape => "Escape",
+            KeyCode::F1 => "F1",
+            KeyCode::F2 => "F2",
+            KeyCode::F3 => "F3",
+            KeyCode::F4 => "F4",
+            KeyCode::F5 => "F5",
+            KeyCode::F6 => "F6",
+            KeyCode::F7 => "F7",
+            KeyCode::F8 => "F8",
+            KeyCode::F9 => "F9",
+            KeyCode::F10 => "F10",
+            KeyCode::F11 => "F11",
+            KeyCode::F12 => "F12",
+            KeyCode::F13 => "F13",
+            KeyCode::F14 => "F14",
+            KeyCode::F15 => "F15",
+            KeyCode::F16 => "F16",
+            KeyCode::F17 => "F17",
+            KeyCode::F18 => "F18",
+            KeyCode::F19 => "F19",
+            KeyCode::F20 => "F20",
+            KeyCode::F21 => "F21",
+            KeyCode::F22 => "F22",
+            KeyCode::F23 => "F23",
+            KeyCode::F24 => "F24",
+            KeyCode::Snapshot => "PrintScreen",
+            KeyCode::Scroll => "ScrollLock",
+            KeyCode::Pause => "Pause",
+            KeyCode::Insert => "Insert",
+            KeyCode::Home => "Home",
+            KeyCode::Delete => "Delete",
+            KeyCode::End => "End",
+            KeyCode::PageDown => "PageDown",
+            KeyCode::PageUp => "PageUp",
+            KeyCode::Left => "ArrowLeft",
+            KeyCode::Up => "ArrowUp",
+            KeyCode::Right => "ArrowRight",
+            KeyCode::Down => "ArrowDown",
+            KeyCode::Back => "Backspace",
+            KeyCode::Return => "Enter",
+            KeyCode::Space => "Space",
+            KeyCode::Compose => "ContextMenu",
+            KeyCode::Caret => "Caret",
+            KeyCode::Numlock => "NumLock",
+            KeyCode::Numpad0 => "Numpad0",
+            KeyCode::Numpad1 => "Numpad1",
+            KeyCode::Numpad2 => "Numpad2",
+            KeyCode::Numpad3 => "Numpad3",
+            KeyCode::Numpad4 => "Numpad4",
+            KeyCode::Numpad5 => "Numpad5",
+            KeyCode::Numpad6 => "Numpad6",
+            KeyCode::Numpad7 => "Numpad7",
+            KeyCode::Numpad8 => "Numpad8",
+            KeyCode::Numpad9 => "Numpad9",
+            KeyCode::AbntC1 => "IntlRo",
+            KeyCode::AbntC2 => "AbntC2",
+            KeyCode::NumpadAdd => "NumpadAdd",
+            KeyCode::Apostrophe => "Quote",
+            KeyCode::Apps => "ContextMenu2",
+            KeyCode::Asterisk => "Asterisk",
+            KeyCode::Plus => "Plus",
+            KeyCode::At => "At",
+            KeyCode::Ax => "Ax",
+            KeyCode::Backslash => "Backslash",
+            KeyCode::Calculator => "LaunchApp2",
+            KeyCode::Capital => "CapsLock",
+            KeyCode::Colon => "Colon",
+            KeyCode::Comma => "Comma",
+            KeyCode::Convert => "Convert",
+            KeyCode::NumpadDecimal => "NumpadDecimal",
+            KeyCode::NumpadDivide => "NumpadDivide",
+            KeyCode::Equals => "Equal",
+            KeyCode::Grave => "Backquote",
+            KeyCode::Kana => "KanaMode",
+            KeyCode::Kanji => "Lang4",
+            KeyCode::LAlt => "AltLeft",
+            KeyCode::LBracket => "BracketLeft",
+            KeyCode::LControl => "ControlLeft",
+            KeyCode::LShift => "ShiftLeft",
+            KeyCode::LWin => "MetaLeft",
+            KeyCode::Mail => "LaunchMail",
+            KeyCode::MediaSelect => "LaunchMediaPlayer",
+            KeyCode::MediaStop => "MediaStop",
+            KeyCode::Minus => "Minus",
+            KeyCode::NumpadMultiply => "NumpadMultiply",
+            KeyCode::Mute => "AudioVolumeMute",
+            KeyCode::MyComputer => "MyComputer",
+            KeyCode::NavigateForward => "NavigateForward",
+            KeyCode::NavigateBackward => "NavigateBackward",
+            KeyCode::NextTrack => "MediaTrackNext",
+            KeyCode::NoConvert => "NonConvert",
+            KeyCode::NumpadComma => "NumpadComma",
+            KeyCode::NumpadEnter => "NumpadEnter",
+            KeyCode::NumpadEquals => "NumpadEqual",
+            KeyCode::OEM102 => "IntlBackslash",
+            KeyCode::Period => "Period",
+            KeyCode::PlayPause => "MediaPlayPause",
+            KeyCode::Power => "Power",
+            KeyCode::PrevTrack => "MediaTrackPrevious",
+            KeyCode::RAlt => "AltRight",
+            KeyCode::RBracket => "BracketRight",
+            KeyCode::RControl => "ControlRight",
+            KeyCode::RShift => "ShiftRight",
+            KeyCode::RWin => "MetaRight",
+            KeyCode::Semicolon => "Semicolon",
+            KeyCode::Slash => "Slash",
+            KeyCode::Sleep => "Sleep",
+            KeyCode::Stop => "Stop",
+            KeyCode::NumpadSubtract => "NumpadSubtract",
+            KeyCode::Sysrq => "SysRq",
+            KeyCode::Tab => "Tab",
+            KeyCode::Underline => "Underline",
+            KeyCode::Unlabeled => "Unlabeled",
+            KeyCode::VolumeDown => "AudioVolumeDown",
+            KeyCode::VolumeUp => "AudioVolumeUp",
+            KeyCode::Wake => "WakeUp",
+            KeyCode::WebBack => "BrowserBack",
+            KeyCode::WebFavorites => "BrowserFavorites",
+            KeyCode::WebForward => "BrowserForward",
+            KeyCode::WebHome => "BrowserHome",
+            KeyCode::WebRefresh => "BrowserRefresh",
+            KeyCode::WebSearch => "BrowserSearch",
+            KeyCode::WebStop => "BrowserStop",
+            KeyCode::Yen => "IntlYen",
+            KeyCode::Copy => "Copy",
+            KeyCode::Paste => "Paste",
+            KeyCode::Cut => "Cut",
+            // Handled separately by `Display`/`FromStr`, since it carries a native code that
+            // doesn't fit in a fixed string table.
+            KeyCode::Unidentified(_) => "Unidentified",
+        }
+    }
+
+    /// A short label for this key, used by [`format_chord`] and [`format_chord_compact`], e.g.
+    /// `"A"` for `KeyCode::A` or `"1"` for `KeyCode::Key1`.
+    fn chord_label(&self) -> String {
+        let name = self.code_name();
+        name.strip_prefix("Key")
+            .or_else(|| name.strip_prefix("Digit"))
+            .unwrap_or(name)
+            .to_string()
+    }
+
+    /// A small set of common aliases accepted by [`FromStr`](std::str::FromStr) in addition to
+    /// the canonical [`code_name`](KeyCode::code_name), for config files written by hand.
+    fn from_alias(name: &str) -> Option<KeyCode> {
+        match name {
+            "Esc" => Some(KeyCode::Escape),
+            "Return" => Some(KeyCode::Return),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for KeyCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KeyCode::Unidentified(NativeKeyCode(code)) => write!(f, "Unidentified({})", code),
+            _ => f.write_str(self.code_name()),
+        }
+    }
+}
+
+/// The error returned when a string doesn't name a known [`KeyCode`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseKeyCodeError(String);
+
+impl std::fmt::Display for ParseKeyCodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "\"{}\" is not a recognized KeyCode", self.0)
+    }
+}
+
+impl std::error::Error for ParseKeyCodeError {}
+
+impl std::str::FromStr for KeyCode {
+    type Err = ParseKeyCodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(code) = s
+            .strip_prefix("Unidentified(")
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            return code
+                .parse::<u32>()
+                .map(|code| KeyCode::Unidentified(NativeKeyCode(code)))
+                .map_err(|_| ParseKeyCodeError(s.to_string()));
+        }
+
+        // The table is small, so a linear scan over `KeyCode::all()` is simplest and needs no
+        // separate name-to-key map. `ALL_KEY_CODES` itself still has to be kept in step with
+        // `code_name` by hand (see `all_key_codes_covers_every_variant`).
+        KeyCode::all()
+            .iter()
+            .copied()
+            .find(|key| key.code_name() == s)
+            .or_else(|| KeyCode::from_alias(s))
+            .ok_or_else(|| ParseKeyCodeError(s.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod key_code_str_tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn all_key_codes_covers_every_variant() {
+        // `code_name`'s match is exhaustive (compiler-enforced) and has one arm per fieldless
+        // variant plus `Unidentified(_)`, so its arm count is the ground truth for how many
+        // entries `ALL_KEY_CODES` should have. If this fails, a variant was added to `KeyCode`
+        // and `code_name` but not to `ALL_KEY_CODES`, and `FromStr` silently can't parse it.
+        const FIELDLESS_VARIANT_COUNT: usize = 163;
+        assert_eq!(KeyCode::all().len(), FIELDLESS_VARIANT_COUNT);
+    }
+
+    #[test]
+    fn every_named_variant_round_trips_through_display_and_from_str() {
+        for key in KeyCode::all() {
+            if matches!(key, KeyCode::Unidentified(_)) {
+                continue;
+            }
+            assert_eq!(KeyCode::from_str(&key.to_string()), Ok(*key));
+        }
+    }
+
+    #[test]
+    fn unidentified_round_trips_through_its_native_code() {
+        let key = KeyCode::Unidentified(NativeKeyCode(1234));
+
+        assert_eq!(key.to_string(), "Unidentified(1234)");
+        assert_eq!(KeyCode::from_str(&key.to_string()), Ok(key));
+    }
+
+    #[test]
+    fn unrecognized_name_is_a_parse_error() {
+        assert_eq!(
+            KeyCode::from_str("NotAKey"),
+            Err(ParseKeyCodeError("NotAKey".to_string()))
+        );
+    }
+}
+
+impl KeyCode {
+    /// Maps this key to a character, assuming an unmodified US-QWERTY layout.
+    #[deprecated(
+        since = "0.5.0",
+        note = "only covers unmodified US-QWERTY and silently gives wrong results under other \
+                layouts or modifiers; use the `ReceivedCharacter`/`ReceivedString` events instead"
+    )]
+    pub fn to_char(self) -> Option<char> {
+        match self {
+            KeyCode::Key1 | KeyCode::Numpad1 => Some('1'),
+            KeyCode::Key2 | KeyCode::Numpad2 => Some('2'),
+            KeyCode::Key3 | KeyCode::Numpad3 => Some('3'),
+            KeyCode::Key4 | KeyCode::Numpad4 => Some('4'),
+            KeyCode::Key5 | KeyCode::Numpad5 => Some('5'),
+            KeyCode::Key6 | KeyCode::Numpad6 => Some('6'),
+            KeyCode::Key7 | KeyCode::Numpad7 => Some('7'),
+            KeyCode::Key8 | KeyCode::Numpad8 => Some('8'),
+            KeyCode::Key9 | KeyCode::Numpad9 => Some('9'),
+            KeyCode::Key0 | KeyCode::Numpad0 => Some('0'),
+            KeyCode::A => Some('a'),
+            KeyCode::B => Some('b'),
+            KeyCode::C => Some('c'),
+            KeyCode::D => Some('d'),
+            KeyCode::E => Some('e'),
+            KeyCode::F => Some('f'),
+            KeyCode::G => Some('g'),
+            KeyCode::H => Some('h'),
+            KeyCode::I => Some('i'),
+            KeyCode::J => Some('j'),
+            KeyCode::K => Some('k'),
+            KeyCode::L => Some('l'),
+            KeyCode::M => Some('m'),
+            KeyCode::N => Some('n'),
+            KeyCode::O => Some('o'),
+            KeyCode::P => Some('p'),
+            KeyCode::Q => Some('q'),
+            KeyCode::R => Some('r'),
+            KeyCode::S => Some('s'),
+            KeyCode::T => Some('t'),
+            KeyCode::U => Some('u'),
+            KeyCode::V => Some('v'),
+            KeyCode::W => Some('w'),
+            KeyCode::X => Some('x'),
+            KeyCode::Y => Some('y'),
+            KeyCode::Z => Some('z'),
+            KeyCode::Caret => Some('^'),
+            KeyCode::Apostrophe => Some('\''),
+            KeyCode::Asterisk | KeyCode::NumpadMultiply => Some('*'),
+            KeyCode::Plus | KeyCode::NumpadAdd => Some('+'),
+            KeyCode::At => Some('@'),
+            KeyCode::Backslash => Some('\\'),
+            KeyCode::Colon => Some(':'),
+            KeyCode::Comma | KeyCode::NumpadComma => Some(','),
+            KeyCode::Period | KeyCode::NumpadDecimal => Some('.'),
+            KeyCode::Slash | KeyCode::NumpadDivide => Some('/'),
+            KeyCode::Equals | KeyCode::NumpadEquals => Some('='),
+            KeyCode::Grave => Some('`'),
+            KeyCode::Minus | KeyCode::NumpadSubtract => Some('-'),
+            KeyCode::Semicolon => Some(';'),
+            KeyCode::Yen => Some('¥'),
+            _ => None,
+        }
+    }
 }
 
 impl TryInto<char> for KeyCode {
     type Error = ();
 
+    #[allow(deprecated)]
     fn try_into(self) -> Result<char, Self::Error> {
-        match self {
-            KeyCode::Key1 | KeyCode::Numpad1 => Ok('1'),
-            KeyCode::Key2 | KeyCode::Numpad2 => Ok('2'),
-            KeyCode::Key3 | KeyCode::Numpad3 => Ok('3'),
-            KeyCode::Key4 | KeyCode::Numpad4 => Ok('4'),
-            KeyCode::Key5 | KeyCode::Numpad5 => Ok('5'),
-            KeyCode::Key6 | KeyCode::Numpad6 => Ok('6'),
-            KeyCode::Key7 | KeyCode::Numpad7 => Ok('7'),
-            KeyCode::Key8 | KeyCode::Numpad8 => Ok('8'),
-            KeyCode::Key9 | KeyCode::Numpad9 => Ok('9'),
-            KeyCode::Key0 | KeyCode::Numpad0 => Ok('0'),
-            KeyCode::A => Ok('a'),
-            KeyCode::B => Ok('b'),
-            KeyCode::C => Ok('c'),
-            KeyCode::D => Ok('d'),
-            KeyCode::E => Ok('e'),
-            KeyCode::F => Ok('f'),
-            KeyCode::G => Ok('g'),
-            KeyCode::H => Ok('h'),
-            KeyCode::I => Ok('i'),
-            KeyCode::J => Ok('j'),
-            KeyCode::K => Ok('k'),
-            KeyCode::L => Ok('l'),
-            KeyCode::M => Ok('m'),
-            KeyCode::N => Ok('n'),
-            KeyCode::O => Ok('o'),
-            KeyCode::P => Ok('p'),
-            KeyCode::Q => Ok('q'),
-            KeyCode::R => Ok('r'),
-            KeyCode::S => Ok('s'),
-            KeyCode::T => Ok('t'),
-            KeyCode::U => Ok('u'),
-            KeyCode::V => Ok('v'),
-            KeyCode::W => Ok('w'),
-            KeyCode::X => Ok('x'),
-            KeyCode::Y => Ok('y'),
-            KeyCode::Z => Ok('z'),
-            KeyCode::Caret => Ok('^'),
-            KeyCode::Apostrophe => Ok('\''),
-            KeyCode::Asterisk | KeyCode::NumpadMultiply => Ok('*'),
-            KeyCode::Plus | KeyCode::NumpadAdd => Ok('+'),
-            KeyCode::At => Ok('@'),
-            KeyCode::Backslash => Ok('\\'),
-            KeyCode::Colon => Ok(':'),
-            KeyCode::Comma | KeyCode::NumpadComma => Ok(','),
-            KeyCode::Period | KeyCode::NumpadDecimal => Ok('.'),
-            KeyCode::Slash | KeyCode::NumpadDivide => Ok('/'),
-            KeyCode::Equals | KeyCode::NumpadEquals => Ok('='),
-            KeyCode::Grave => Ok('`'),
-            KeyCode::Minus | KeyCode::NumpadSubtract => Ok('-'),
-            KeyCode::Semicolon => Ok(';'),
-            KeyCode::Yen => Ok('¥'),
-            _ => Err(()),
+        self.to_char().ok_or(())
+    }
+}
+
+/// Which modifier keys a [`Binding`] requires, independent of left/right side.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ModifierMask {
+    pub shift: bool,
+    pub control: bool,
+    pub alt: bool,
+    pub win: bool,
+}
+
+impl ModifierMask {
+    fn count(&self) -> usize {
+        self.shift as usize + self.control as usize + self.alt as usize + self.win as usize
+    }
+
+    fn is_satisfied_by(&self, modifiers: &Modifiers) -> bool {
+        (!self.shift || modifiers.shift())
+            && (!self.control || modifiers.control())
+            && (!self.alt || modifiers.alt())
+            && (!self.win || modifiers.win())
+    }
+
+    fn is_superset_of(&self, other: &ModifierMask) -> bool {
+        (self.shift || !other.shift)
+            && (self.control || !other.control)
+            && (self.alt || !other.alt)
+            && (self.win || !other.win)
+    }
+}
+
+/// A single key, optionally combined with modifiers held at the same time (e.g. Ctrl+S), bound
+/// to an action in an [`InputMap`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Binding {
+    key: KeyCode,
+    modifiers: ModifierMask,
+}
+
+impl Binding {
+    /// A binding satisfied by `key` alone, with no modifiers required.
+    pub fn key(key: KeyCode) -> Self {
+        Binding {
+            key,
+            modifiers: ModifierMask::default(),
+        }
+    }
+
+    /// Requires either Shift key to also be held.
+    pub fn with_shift(mut self) -> Self {
+        self.modifiers.shift = true;
+        self
+    }
+
+    /// Requires either Control key to also be held.
+    pub fn with_control(mut self) -> Self {
+        self.modifiers.control = true;
+        self
+    }
+
+    /// Requires either Alt key to also be held.
+    pub fn with_alt(mut self) -> Self {
+        self.modifiers.alt = true;
+        self
+    }
+
+    /// Requires either Super/Meta/Windows key to also be held.
+    pub fn with_win(mut self) -> Self {
+        self.modifiers.win = true;
+        self
+    }
+
+    fn is_satisfied(&self, keyboard_input: &Input<KeyCode>, modifiers: &Modifiers) -> bool {
+        keyboard_input.pressed(self.key) && self.modifiers.is_satisfied_by(modifiers)
+    }
+
+    /// The number of keys (including modifiers) this binding requires; used to pick a winner
+    /// when two satisfiable bindings clash.
+    fn key_count(&self) -> usize {
+        1 + self.modifiers.count()
+    }
+
+    /// Whether `other`'s key and modifiers are all also required by `self`, and `self` requires
+    /// more keys overall, i.e. `self` is a strict superset of `other` (e.g. Ctrl+S is a superset
+    /// of S).
+    fn is_superset_of(&self, other: &Binding) -> bool {
+        self.key_count() > other.key_count()
+            && self.key == other.key
+            && self.modifiers.is_superset_of(&other.modifiers)
+    }
+}
+
+/// Maps an action of type `A` to one or more [`Binding`]s (single keys or chords), read each
+/// frame by [`action_input_system`] to update an [`ActionState<A>`].
+///
+/// This is the layer gameplay code should sit on instead of reading raw `KeyCode`s, so controls
+/// can be rebound and loaded from a config file.
+#[derive(Debug, Clone)]
+pub struct InputMap<A> {
+    bindings: HashMap<A, Vec<Binding>>,
+}
+
+impl<A> Default for InputMap<A> {
+    fn default() -> Self {
+        InputMap {
+            bindings: HashMap::new(),
+        }
+    }
+}
+
+impl<A: Eq + Hash> InputMap<A> {
+    /// Binds `action` to `binding`, in addition to any bindings it already has.
+    pub fn bind(&mut self, action: A, binding: Binding) -> &mut Self {
+        self.bindings.entry(action).or_default().push(binding);
+        self
+    }
+
+    /// The bindings currently registered for `action`.
+    pub fn bindings(&self, action: &A) -> &[Binding] {
+        self.bindings
+            .get(action)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+}
+
+/// Whether each action of type `A` is currently pressed, updated each frame by
+/// [`action_input_system`] from an [`InputMap<A>`].
+#[derive(Debug, Clone)]
+pub struct ActionState<A> {
+    pressed: HashSet<A>,
+    just_pressed: HashSet<A>,
+    just_released: HashSet<A>,
+}
+
+impl<A> Default for ActionState<A> {
+    fn default() -> Self {
+        ActionState {
+            pressed: HashSet::new(),
+            just_pressed: HashSet::new(),
+            just_released: HashSet::new(),
+        }
+    }
+}
+
+impl<A: Eq + Hash> ActionState<A> {
+    /// Whether `action` is currently held.
+    pub fn pressed(&self, action: &A) -> bool {
+        self.pressed.contains(action)
+    }
+
+    /// Whether `action` started being held this frame.
+    pub fn just_pressed(&self, action: &A) -> bool {
+        self.just_pressed.contains(action)
+    }
+
+    /// Whether `action` stopped being held this frame.
+    pub fn just_released(&self, action: &A) -> bool {
+        self.just_released.contains(action)
+    }
+}
+
+impl<A: Eq + Hash + Clone> ActionState<A> {
+    fn update(&mut self, pressed_now: HashSet<A>) {
+        self.just_pressed = pressed_now.difference(&self.pressed).cloned().collect();
+        self.just_released = self.pressed.difference(&pressed_now).cloned().collect();
+        self.pressed = pressed_now;
+    }
+}
+
+/// Resolves which actions of an [`InputMap<A>`] are satisfied by the current `Input<KeyCode>`
+/// and [`Modifiers`] state, suppressing bindings that clash with a longer satisfied binding.
+///
+/// When two bound inputs are simultaneously satisfiable and one binding is a strict superset of
+/// another (e.g. both `S` and `Ctrl+S` are bound, and Ctrl is held), the binding requiring more
+/// keys wins and the shorter one is suppressed for that frame. Ties and disjoint bindings all
+/// fire, so binding two unrelated actions to `S` and `A` never suppresses either.
+fn resolve_actions<A: Eq + Hash + Clone>(
+    input_map: &InputMap<A>,
+    keyboard_input: &Input<KeyCode>,
+    modifiers: &Modifiers,
+) -> HashSet<A> {
+    let satisfied: Vec<(&A, &Binding)> = input_map
+        .bindings
+        .iter()
+        .flat_map(|(action, bindings)| bindings.iter().map(move |binding| (action, binding)))
+        .filter(|(_, binding)| binding.is_satisfied(keyboard_input, modifiers))
+        .collect();
+
+    let mut pressed_now = HashSet::new();
+    'actions: for (action, binding) in &satisfied {
+        for (_, other) in &satisfied {
+            if other.is_superset_of(binding) {
+                continue 'actions;
+            }
+        }
+        pressed_now.insert((*action).clone());
+    }
+    pressed_now
+}
+
+/// Updates an [`ActionState<A>`] from an [`InputMap<A>`] by reading the current `Input<KeyCode>`
+/// and [`Modifiers`] state each frame. See [`resolve_actions`] for the clash-resolution rules.
+pub fn action_input_system<A: Send + Sync + 'static + Eq + Hash + Clone>(
+    input_map: Res<InputMap<A>>,
+    keyboard_input: Res<Input<KeyCode>>,
+    modifiers: Res<Modifiers>,
+    mut action_state: ResMut<ActionState<A>>,
+) {
+    let pressed_now = resolve_actions(&input_map, &keyboard_input, &modifiers);
+    action_state.update(pressed_now);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum TestAction {
+        Save,
+        Other,
+        Unrelated,
+    }
+
+    fn keyboard_input(pressed: &[KeyCode]) -> Input<KeyCode> {
+        let mut input = Input::default();
+        for key in pressed {
+            input.press(*key);
+        }
+        input
+    }
+
+    fn modifiers(pressed: &[KeyCode]) -> Modifiers {
+        let mut modifiers = Modifiers::default();
+        for key in pressed {
+            modifiers.update(*key, true);
         }
+        modifiers
+    }
+
+    fn resolve(input_map: &InputMap<TestAction>, pressed: &[KeyCode]) -> HashSet<TestAction> {
+        resolve_actions(input_map, &keyboard_input(pressed), &modifiers(pressed))
+    }
+
+    #[test]
+    fn longer_chord_suppresses_shorter_clash() {
+        let mut input_map = InputMap::default();
+        input_map.bind(TestAction::Other, Binding::key(KeyCode::S));
+        input_map.bind(TestAction::Save, Binding::key(KeyCode::S).with_control());
+
+        let pressed = resolve(&input_map, &[KeyCode::LControl, KeyCode::S]);
+
+        assert_eq!(pressed, HashSet::from([TestAction::Save]));
+    }
+
+    #[test]
+    fn tied_bindings_both_fire() {
+        let mut input_map = InputMap::default();
+        input_map.bind(TestAction::Save, Binding::key(KeyCode::S));
+        input_map.bind(TestAction::Other, Binding::key(KeyCode::S));
+
+        let pressed = resolve(&input_map, &[KeyCode::S]);
+
+        assert_eq!(
+            pressed,
+            HashSet::from([TestAction::Save, TestAction::Other])
+        );
+    }
+
+    #[test]
+    fn disjoint_bindings_both_fire() {
+        let mut input_map = InputMap::default();
+        input_map.bind(TestAction::Save, Binding::key(KeyCode::S).with_control());
+        input_map.bind(TestAction::Unrelated, Binding::key(KeyCode::A));
+
+        let pressed = resolve(&input_map, &[KeyCode::LControl, KeyCode::S, KeyCode::A]);
+
+        assert_eq!(
+            pressed,
+            HashSet::from([TestAction::Save, TestAction::Unrelated])
+        );
+    }
+
+    #[test]
+    fn one_action_with_two_bindings_is_not_self_suppressed() {
+        let mut input_map = InputMap::default();
+        input_map.bind(TestAction::Save, Binding::key(KeyCode::S).with_control());
+        input_map.bind(TestAction::Save, Binding::key(KeyCode::S));
+
+        let pressed = resolve(&input_map, &[KeyCode::LControl, KeyCode::S]);
+
+        assert_eq!(pressed, HashSet::from([TestAction::Save]));
+    }
+
+    #[test]
+    fn binding_is_superset_of_requires_more_keys() {
+        let ctrl_s = Binding::key(KeyCode::S).with_control();
+        let s = Binding::key(KeyCode::S);
+
+        assert!(ctrl_s.is_superset_of(&s));
+        assert!(!s.is_superset_of(&ctrl_s));
+        assert!(!s.is_superset_of(&s));
+    }
+
+    #[test]
+    fn modifier_mask_is_superset_of() {
+        let ctrl_shift = ModifierMask {
+            shift: true,
+            control: true,
+            alt: false,
+            win: false,
+        };
+        let ctrl = ModifierMask {
+            shift: false,
+            control: true,
+            alt: false,
+            win: false,
+        };
+
+        assert!(ctrl_shift.is_superset_of(&ctrl));
+        assert!(!ctrl.is_superset_of(&ctrl_shift));
     }
 }